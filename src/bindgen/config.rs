@@ -0,0 +1,56 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+
+/// The language to generate bindings for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    Cxx,
+    C,
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Language::Cxx
+    }
+}
+
+/// Settings for how item names are exported into the generated header.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ExportConfig {}
+
+impl ExportConfig {
+    /// Applies the configured renaming rules to an exported name in place.
+    pub fn rename(&self, _name: &mut String) {}
+}
+
+/// Settings specific to generated `typedef`/`using` declarations.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct TypedefConfig {
+    /// Whether to append a doc comment noting that the value may be
+    /// null/absent when a typedef aliases `Option<T>`, whose C
+    /// representation otherwise gives no hint that it's optional.
+    pub document_option_nullability: bool,
+}
+
+impl Default for TypedefConfig {
+    fn default() -> Self {
+        Self {
+            document_option_nullability: false,
+        }
+    }
+}
+
+/// Top-level configuration for a cbindgen run.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Config {
+    pub language: Language,
+    pub export: ExportConfig,
+    pub typedef: TypedefConfig,
+}
@@ -29,25 +29,91 @@ pub struct Typedef {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    /// Concrete values to substitute for `generic_params` when synthesizing
+    /// this alias's default instantiation (see `instantiate_default_generics`),
+    /// set when every generic parameter has a default type and the alias
+    /// carries no `cbindgen:concretize` annotation. `self` stays
+    /// generic either way, so usage-driven monomorphization (e.g. a
+    /// `Handle<i64>` elsewhere in the crate) is unaffected.
+    default_generic_values: Option<Vec<Type>>,
 }
 
 impl Typedef {
     pub fn load(item: &syn::ItemType, mod_cfg: &Option<Cfg>) -> Result<Typedef, String> {
         if let Some(x) = Type::load(&item.ty)? {
             let path = Path::new(item.ident.to_string());
-            Ok(Typedef::new(
+            let generic_params = GenericParams::new(&item.generics);
+            let annotations = AnnotationSet::load(&item.attrs)?;
+
+            let mut typedef = Typedef::new(
                 path,
-                GenericParams::new(&item.generics),
+                generic_params,
                 x,
                 Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
-                AnnotationSet::load(&item.attrs)?,
+                annotations,
                 Documentation::load(&item.attrs),
-            ))
+            );
+
+            let defaults =
+                Self::default_generic_values(&item.generics, &typedef.generic_params)?;
+            if let Some(defaults) = defaults {
+                if typedef.annotations.list("cbindgen:concretize").is_some() {
+                    // An explicit `cbindgen:concretize` annotation always
+                    // wins over the implicit defaults.
+                    warn!(
+                        "{} has both `cbindgen:concretize` and fully-defaulted generic parameters. \
+                         Honoring `cbindgen:concretize` and ignoring the defaults.",
+                        typedef.path,
+                    );
+                } else {
+                    typedef.default_generic_values = Some(defaults);
+                }
+            }
+
+            Ok(typedef)
         } else {
             Err("Cannot have a typedef of a zero sized type.".to_owned())
         }
     }
 
+    /// Returns the default type argument for each generic parameter,
+    /// provided the alias is generic and *all* of its parameters have a
+    /// default (e.g. `type Handle<T = u32> = *mut T;`). Returns `Ok(None)`
+    /// if the alias isn't generic, or any parameter is missing a default,
+    /// in which case it keeps monomorphizing on use only, as before.
+    fn default_generic_values(
+        generics: &syn::Generics,
+        generic_params: &GenericParams,
+    ) -> Result<Option<Vec<Type>>, String> {
+        if generic_params.len() == 0 {
+            return Ok(None);
+        }
+
+        let mut defaults = Vec::with_capacity(generic_params.len());
+        for param in &generics.params {
+            let type_param = match param {
+                syn::GenericParam::Type(type_param) => type_param,
+                _ => continue,
+            };
+
+            let default = match &type_param.default {
+                Some(default) => default,
+                None => return Ok(None),
+            };
+
+            match Type::load(default)? {
+                Some(ty) => defaults.push(ty),
+                None => return Ok(None),
+            }
+        }
+
+        if defaults.len() != generic_params.len() {
+            return Ok(None);
+        }
+
+        Ok(Some(defaults))
+    }
+
     pub fn new(
         path: Path,
         generic_params: GenericParams,
@@ -65,6 +131,7 @@ impl Typedef {
             cfg,
             annotations,
             documentation,
+            default_generic_values: None,
         }
     }
 
@@ -72,25 +139,59 @@ impl Typedef {
         self.aliased.simplify_standard_types();
     }
 
-    pub fn transfer_annotations(&mut self, out: &mut HashMap<Path, AnnotationSet>) {
+    pub fn transfer_annotations(
+        &mut self,
+        library: &Library,
+        out: &mut HashMap<Path, AnnotationSet>,
+    ) {
         if self.annotations.is_empty() {
             return;
         }
 
-        match self.aliased.get_root_path() {
-            Some(alias_path) => {
-                if out.contains_key(&alias_path) {
-                    warn!(
-                        "Multiple typedef's with annotations for {}. Ignoring annotations from {}.",
-                        alias_path, self.path
-                    );
+        // Follow the chain of aliases (`type A = B; type B = C;`) until we
+        // reach an item that isn't itself a typedef, or a generic boundary,
+        // transferring the annotations onto that final target.
+        let mut current_aliased = &self.aliased;
+        let mut visited = vec![self.path.clone()];
+
+        loop {
+            let alias_path = match current_aliased.get_root_path() {
+                Some(alias_path) => alias_path,
+                None => return,
+            };
+
+            if visited.contains(&alias_path) {
+                warn!(
+                    "Cycle detected while transferring annotations from {} through {}. Ignoring annotations.",
+                    self.path, alias_path,
+                );
+                return;
+            }
+
+            // Stop at a typedef that is itself still generic: its `aliased`
+            // type may just be a bare generic parameter (e.g. `T` in
+            // `type Foo<T> = T;`), whose root path doesn't name a real item.
+            // Transfer onto the generic typedef itself rather than chasing
+            // that placeholder.
+            match library.get_typedef(&alias_path) {
+                Some(next) if !next.is_generic() => {
+                    visited.push(alias_path);
+                    current_aliased = &next.aliased;
+                }
+                _ => {
+                    if out.contains_key(&alias_path) {
+                        warn!(
+                            "Multiple typedef's with annotations for {}. Ignoring annotations from {}.",
+                            alias_path, self.path
+                        );
+                        return;
+                    }
+
+                    out.insert(alias_path, self.annotations.clone());
+                    self.annotations = AnnotationSet::new();
                     return;
                 }
-
-                out.insert(alias_path, self.annotations.clone());
-                self.annotations = AnnotationSet::new();
             }
-            None => {}
         }
     }
 
@@ -102,15 +203,89 @@ impl Typedef {
         // Generic structs can instantiate monomorphs only once they've been
         // instantiated. See `instantiate_monomorph` for more details.
         if self.is_generic() {
+            // The exception is an alias that names its own instantiation,
+            // through `cbindgen:concretize` or a default on every parameter.
+            self.instantiate_concretize_annotation(library, out);
+            self.instantiate_default_generics(library, out);
             return;
         }
 
         self.aliased.add_monomorphs(library, out);
     }
 
+    /// If every generic parameter of this alias has a default (see `load`),
+    /// register that default instantiation as a monomorph so the alias
+    /// still produces C output even when it's never used monomorphically.
+    /// This only adds an entry to `out`; `self` is left untouched, so
+    /// genuine usage elsewhere (e.g. `Handle<i64>`) still monomorphizes
+    /// independently through the normal path.
+    fn instantiate_default_generics(&self, library: &Library, out: &mut Monomorphs) {
+        if let Some(values) = &self.default_generic_values {
+            self.instantiate_monomorph(values, library, out);
+        }
+    }
+
+    /// A generic alias that is never used monomorphically would otherwise
+    /// produce no C output at all. If it carries a `cbindgen:concretize`
+    /// annotation naming a concrete type argument for each generic
+    /// parameter, force-instantiate it anyway so library authors can expose
+    /// a stable C name for the one instantiation they care about.
+    fn instantiate_concretize_annotation(&self, library: &Library, out: &mut Monomorphs) {
+        let values = match self.annotations.list("cbindgen:concretize") {
+            Some(values) => values,
+            None => return,
+        };
+
+        if values.len() != self.generic_params.len() {
+            warn!(
+                "{} has {} generic parameters but `cbindgen:concretize` names {}. Ignoring.",
+                self.path,
+                self.generic_params.len(),
+                values.len(),
+            );
+            return;
+        }
+
+        let mut generic_values = Vec::with_capacity(values.len());
+        for value in &values {
+            let syn_ty = match syn::parse_str::<syn::Type>(value) {
+                Ok(syn_ty) => syn_ty,
+                Err(_) => {
+                    warn!(
+                        "Couldn't parse `{}` named in `cbindgen:concretize` on {}. Ignoring.",
+                        value, self.path,
+                    );
+                    return;
+                }
+            };
+
+            match Type::load(&syn_ty) {
+                Ok(Some(ty)) => generic_values.push(ty),
+                _ => {
+                    warn!(
+                        "Couldn't resolve `{}` named in `cbindgen:concretize` on {}. Ignoring.",
+                        value, self.path,
+                    );
+                    return;
+                }
+            }
+        }
+
+        self.instantiate_monomorph(&generic_values, library, out);
+    }
+
     pub fn mangle_paths(&mut self, monomorphs: &Monomorphs) {
         self.aliased.mangle_paths(monomorphs);
     }
+
+    /// `Option<T>` collapses to the same C representation as `T` for
+    /// pointer-like and opaque types, which otherwise gives C consumers no
+    /// hint that the value may be null.
+    fn aliases_option(&self) -> bool {
+        self.aliased
+            .get_root_path()
+            .map_or(false, |path| path.name() == "Option")
+    }
 }
 
 impl Item for Typedef {
@@ -199,7 +374,13 @@ impl Source for Typedef {
         let condition = (&self.cfg).to_condition(config);
         condition.write_before(config, out);
 
-        self.documentation.write(config, out);
+        let mut documentation = self.documentation.clone();
+        if config.typedef.document_option_nullability && self.aliases_option() {
+            documentation
+                .doc_comment
+                .push("NOTE: This value may be null/absent.".to_owned());
+        }
+        documentation.write(config, out);
 
         self.generic_params.write(config, out);
 
@@ -0,0 +1,40 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+use bindgen::ir::{AnnotationSet, Path};
+use bindgen::ir::typedef::Typedef;
+
+/// The set of items gathered while parsing a crate, used to resolve
+/// cross-references (aliases, monomorphs, dependencies) during codegen.
+#[derive(Debug, Default)]
+pub struct Library {
+    typedefs: HashMap<Path, Typedef>,
+}
+
+impl Library {
+    /// Looks up a typedef by its path, used to follow alias chains when
+    /// transferring annotations and resolving monomorphs.
+    pub fn get_typedef(&self, path: &Path) -> Option<&Typedef> {
+        self.typedefs.get(path)
+    }
+
+    /// Transfers annotations from typedefs onto the concrete items they
+    /// alias, following chains of plain aliases to their terminal target.
+    pub fn transfer_annotations(&mut self) -> HashMap<Path, AnnotationSet> {
+        let mut out = HashMap::new();
+
+        let paths: Vec<Path> = self.typedefs.keys().cloned().collect();
+        for path in paths {
+            // Pull the typedef out so we can pass `&self` (for alias-chain
+            // lookups) into its own `&mut self` call without aliasing.
+            let mut typedef = self.typedefs.remove(&path).unwrap();
+            typedef.transfer_annotations(self, &mut out);
+            self.typedefs.insert(path, typedef);
+        }
+
+        out
+    }
+}
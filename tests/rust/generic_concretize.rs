@@ -0,0 +1,11 @@
+// A generic alias that is never used monomorphically anywhere in this
+// crate. Without `cbindgen:concretize` it would produce no C output at all;
+// the annotation forces a single, blessed instantiation to be emitted.
+
+#[repr(C)]
+pub struct Box<T> {
+    ptr: *mut T,
+}
+
+/// cbindgen:concretize=[i32]
+pub type IntBox<T> = Box<T>;
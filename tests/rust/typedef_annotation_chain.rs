@@ -0,0 +1,27 @@
+// `transfer_annotations` should follow a chain of plain aliases down to the
+// concrete item that actually needs the annotation. `derive-eq` is a good
+// probe because the crate-wide default (set to `true` in this test's
+// config) is visible in the generated C++ as `operator==`; if the
+// annotation failed to reach `Concrete`, this struct would keep the
+// default and the header would look identical either way.
+#[repr(C)]
+pub struct Concrete {
+    x: i32,
+}
+
+/// cbindgen:derive-eq=false
+pub type A = B;
+pub type B = C;
+pub type C = Concrete;
+
+// A chain that bottoms out on a still-generic typedef: the walk should stop
+// at `GenericAlias` itself rather than following it on to `Wrapper`, so
+// `Wrapper_i32` keeps the default `operator==`.
+#[repr(C)]
+pub struct Wrapper<T> {
+    inner: T,
+}
+
+/// cbindgen:derive-eq=false
+pub type ViaGeneric = GenericAlias<i32>;
+pub type GenericAlias<T> = Wrapper<T>;
@@ -0,0 +1,6 @@
+// A cyclic alias chain must be detected and abandoned (with a warning)
+// instead of looping forever.
+
+/// cbindgen:derive-eq=false
+pub type X = Y;
+pub type Y = X;
@@ -0,0 +1,19 @@
+// Every parameter has a default: even with no direct usage, cbindgen
+// synthesizes a default instantiation (`Handle_u32`). The alias itself
+// stays generic, so using it elsewhere with a non-default argument (e.g.
+// `Handle<i64>`) still monomorphizes independently through the normal path.
+pub type Handle<T = u32> = *mut T;
+
+pub fn use_handle_i64(x: Handle<i64>) {}
+
+#[repr(C)]
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+// Only some parameters are defaulted: stays generic and only monomorphizes
+// on use, same as before this change.
+pub type DefaultPair<T, U = u32> = Pair<T, U>;
+
+pub fn use_default_pair(x: DefaultPair<u8, u8>) {}
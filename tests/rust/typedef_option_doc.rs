@@ -0,0 +1,10 @@
+// `Option<*mut Opaque>` collapses to the same representation as
+// `*mut Opaque` in C, so the generated typedef gets an extra doc note
+// spelling out that the value may be null.
+
+#[repr(C)]
+pub struct Opaque {
+    _private: [u8; 0],
+}
+
+pub type MaybeOpaque = Option<*mut Opaque>;
@@ -0,0 +1,10 @@
+// Same shape as `typedef_option_doc.rs`, but without the config flag
+// enabled: `document_option_nullability` defaults to off, so no note should
+// be emitted.
+
+#[repr(C)]
+pub struct Opaque {
+    _private: [u8; 0],
+}
+
+pub type MaybeOpaque = Option<*mut Opaque>;